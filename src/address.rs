@@ -1,4 +1,9 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Address {
@@ -53,3 +58,177 @@ impl Display for Address {
         )
     }
 }
+
+#[derive(Error, Debug)]
+pub enum AddressParseError {
+    #[error("expected 6 colon-separated octets, found {}.", groups)]
+    UnexpectedGroupCount { groups: usize },
+    #[error("invalid hex octet {:?}.", octet)]
+    InvalidOctet { octet: String },
+}
+
+impl FromStr for Address {
+    type Err = AddressParseError;
+
+    /// Parses the `Display` format, `aa:bb:cc:dd:ee:ff`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let groups: Vec<&str> = s.split(':').collect();
+        if groups.len() != 6 {
+            return Err(AddressParseError::UnexpectedGroupCount {
+                groups: groups.len(),
+            });
+        }
+
+        let mut bytes = [0u8; 6];
+        for (i, group) in groups.iter().enumerate() {
+            bytes[5 - i] = u8::from_str_radix(group, 16).map_err(|_| {
+                AddressParseError::InvalidOctet {
+                    octet: group.to_string(),
+                }
+            })?;
+        }
+
+        Ok(Address { bytes })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Address {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            self.bytes.serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Address::from_str(&s).map_err(serde::de::Error::custom)
+        } else {
+            let bytes = <[u8; 6]>::deserialize(deserializer)?;
+            Ok(Address::from(bytes))
+        }
+    }
+}
+
+/// Whether a device address is a fixed public address or a random one, as used on LE.
+///
+/// See Bluetooth Core Specification v5.2, Vol 6, Part B, 1.3.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressType {
+    Public,
+    Random,
+}
+
+/// The kind of a random LE device address, selected by the two most significant bits of its
+/// top octet.
+///
+/// See Bluetooth Core Specification v5.2, Vol 6, Part B, 1.3.2.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RandomAddressKind {
+    NonResolvablePrivate,
+    ResolvablePrivate,
+    /// The `10` top-bit pattern, reserved by the spec for future use.
+    Reserved,
+    Static,
+}
+
+/// A device address tagged with whether it is public or random, as seen on LE.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeAddress {
+    address: Address,
+    address_type: AddressType,
+}
+
+impl LeAddress {
+    pub fn new(address: Address, address_type: AddressType) -> LeAddress {
+        LeAddress {
+            address,
+            address_type,
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    pub fn address_type(&self) -> AddressType {
+        self.address_type
+    }
+
+    /// Classifies a random address by the two most significant bits of its top octet.
+    ///
+    /// Returns `None` if this is a public address, since the classification only applies to
+    /// random addresses.
+    pub fn random_kind(&self) -> Option<RandomAddressKind> {
+        if self.address_type != AddressType::Random {
+            return None;
+        }
+
+        Some(match self.address.bytes[5] >> 6 {
+            0b00 => RandomAddressKind::NonResolvablePrivate,
+            0b01 => RandomAddressKind::ResolvablePrivate,
+            0b11 => RandomAddressKind::Static,
+            _ => RandomAddressKind::Reserved,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn from_str_test() {
+        let address: Address = "aa:bb:cc:dd:ee:ff".parse().unwrap();
+        assert_eq!(address.to_string(), "aa:bb:cc:dd:ee:ff");
+    }
+
+    #[test]
+    pub fn from_str_invalid_group_count_test() {
+        assert!(matches!(
+            "aa:bb:cc".parse::<Address>(),
+            Err(AddressParseError::UnexpectedGroupCount { groups: 3 })
+        ));
+    }
+
+    #[test]
+    pub fn from_str_invalid_octet_test() {
+        assert!(matches!(
+            "aa:bb:cc:dd:ee:zz".parse::<Address>(),
+            Err(AddressParseError::InvalidOctet { .. })
+        ));
+    }
+
+    #[test]
+    pub fn public_address_has_no_random_kind_test() {
+        let address = LeAddress::new(Address::zero(), AddressType::Public);
+        assert_eq!(address.random_kind(), None);
+    }
+
+    #[test]
+    pub fn random_address_kind_test() {
+        let cases = [
+            (0b00_000000u8, RandomAddressKind::NonResolvablePrivate),
+            (0b01_000000u8, RandomAddressKind::ResolvablePrivate),
+            (0b10_000000u8, RandomAddressKind::Reserved),
+            (0b11_000000u8, RandomAddressKind::Static),
+        ];
+
+        for (top_byte, expected) in cases {
+            let address = LeAddress::new(
+                Address::from([0, 0, 0, 0, 0, top_byte]),
+                AddressType::Random,
+            );
+            assert_eq!(address.random_kind(), Some(expected));
+        }
+    }
+}
@@ -10,10 +10,12 @@ use enumflags2::BitFlags;
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
 use thiserror::Error;
+use uuid::Uuid;
 
 /// See CSS v9 Part A 1.3.2 for flag meaning.
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, BitFlags, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EIRFlags {
     LELimitedDiscoverableMode = 1 << 0,
     LEGeneralDiscoverableMode = 1 << 1,
@@ -44,12 +46,59 @@ impl EIRName {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManufacturerSpecificData {
     company_identifier_code: u16,
     data: Bytes,
 }
 
+impl ManufacturerSpecificData {
+    pub(crate) fn company_identifier_code(&self) -> u16 {
+        self.company_identifier_code
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Which Service Data AD type a [`ServiceData`] came from (or should be written as), i.e. the
+/// width of its UUID before expansion.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UuidWidth {
+    Bits16,
+    Bits32,
+    Bits128,
+}
+
+/// Service Data, as found in the 16-bit, 32-bit, and 128-bit Service Data AD types. `uuid` is
+/// widened to `u128` regardless of which AD type it came from; `width` records which one it was
+/// so it can be expanded against the Bluetooth Base UUID correctly.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceData {
+    uuid: u128,
+    width: UuidWidth,
+    data: Bytes,
+}
+
+impl ServiceData {
+    /// Expands `uuid` to its full 128-bit form based on the AD type `width` it was parsed from.
+    pub(crate) fn uuid128(&self) -> u128 {
+        match self.width {
+            UuidWidth::Bits16 | UuidWidth::Bits32 => to_uuid128(self.uuid as u32),
+            UuidWidth::Bits128 => self.uuid,
+        }
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EIR {
     Flags(BitFlags<EIRFlags>),
     Uuid16(Vec<u16>),
@@ -59,6 +108,72 @@ pub enum EIR {
     TxPowerLevel(Vec<i8>),
     Uri(Vec<String>),
     ManufacturerSpecificData(Vec<ManufacturerSpecificData>),
+    ServiceData(Vec<ServiceData>),
+}
+
+impl EIR {
+    /// Returns the UUIDs of a UUID list structure (`Uuid16`, `Uuid32` or `Uuid128`), expanded to
+    /// full 128-bit form.
+    ///
+    /// 16-bit and 32-bit UUIDs are expanded via [`to_uuid128`]; every other variant, including
+    /// `ServiceData`, yields an empty `Vec`.
+    pub fn uuids(&self) -> Vec<Uuid> {
+        match self {
+            EIR::Uuid16(uuids) => uuids
+                .iter()
+                .map(|uuid| Uuid::from_u128(to_uuid128(*uuid as u32)))
+                .collect(),
+            EIR::Uuid32(uuids) => uuids
+                .iter()
+                .map(|uuid| Uuid::from_u128(to_uuid128(*uuid)))
+                .collect(),
+            EIR::Uuid128(uuids) => uuids.iter().map(|uuid| Uuid::from_u128(*uuid)).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The Bluetooth Base UUID, `00000000-0000-1000-8000-00805F9B34FB`, used to expand 16-bit and
+/// 32-bit UUIDs to their full 128-bit form.
+///
+/// See Bluetooth Core Specification v5.2, Vol 3, Part B, 2.5.1.
+const BLUETOOTH_BASE_UUID: u128 = 0x0000_0000_0000_1000_8000_0080_5F9B_34FB;
+
+/// Expands a 16-bit or 32-bit UUID (zero-extended to 32 bits) to its full 128-bit form by
+/// placing it in the most-significant 32 bits of the Bluetooth Base UUID.
+pub fn to_uuid128(short: u32) -> u128 {
+    BLUETOOTH_BASE_UUID | ((short as u128) << 96)
+}
+
+/// Bluetooth "URI Scheme Name String Mapping" assigned-numbers table (partial): maps a URI
+/// scheme code to its textual prefix. `0x00` is handled by callers directly, since it means the
+/// scheme is already present inline in the string.
+const URI_SCHEMES: &[(u8, &str)] = &[
+    (0x01, "aaa:"),
+    (0x16, "http:"),
+    (0x17, "https:"),
+    (0x18, "ftp:"),
+    (0x19, "tel:"),
+    (0x1B, "urn:"),
+];
+
+/// Looks up the textual prefix for a URI scheme code from `URI_SCHEMES`.
+fn uri_scheme_prefix(code: u8) -> Option<&'static str> {
+    URI_SCHEMES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, prefix)| *prefix)
+}
+
+/// Picks the best-matching URI scheme code for `uri`, stripping the corresponding prefix. Falls
+/// back to `0x00` (scheme included inline) if no known prefix matches.
+fn encode_uri_scheme(uri: &str) -> (u8, &str) {
+    for (code, prefix) in URI_SCHEMES {
+        if let Some(suffix) = uri.strip_prefix(prefix) {
+            return (*code, suffix);
+        }
+    }
+    (0x00, uri)
 }
 
 #[derive(Error, Debug)]
@@ -71,6 +186,10 @@ pub enum EIRError {
     UnexpectedDataLength { len: usize },
     #[error("UTF-8 encoding error in URI.")]
     InvalidURI,
+    #[error("Unrecognized URI scheme code {:#04x}.", code)]
+    UnknownURIScheme { code: u8 },
+    #[error("Encoded advertising data length {} exceeds the {}-byte legacy limit.", len, MAX_LEGACY_ADV_LEN)]
+    AdvertisingDataTooLong { len: usize },
 }
 
 #[repr(u8)]
@@ -87,19 +206,27 @@ enum EIRDataTypes {
     NameShort = 0x08,
     NameComplete = 0x09,
     TxPowerLevel = 0x0A,
+    ServiceData16 = 0x16,
+    ServiceData32 = 0x20,
+    ServiceData128 = 0x21,
     URI = 0x24,
     ManufacturerSpecificData = 0xFF,
 }
 
 /// Parses Extended Inquiry Response (EIR) Data.
 ///
-/// This will silently skip any unknown data types or URIs using
-/// encoded schemes.
+/// This will silently skip any unknown data types.
 pub fn parse_eir<T: Buf>(mut buf: T) -> Result<Vec<EIR>, EIRError> {
     let mut eir : Vec<EIR> = Vec::new();
     let mut has_flag = false;
     let mut has_name = false;
     let mut uuid16_idx : Option<usize> = None;
+    let mut uuid32_idx : Option<usize> = None;
+    let mut uuid128_idx : Option<usize> = None;
+    let mut tx_power_idx : Option<usize> = None;
+    let mut uri_idx : Option<usize> = None;
+    let mut manufacturer_idx : Option<usize> = None;
+    let mut service_data_idx : Option<usize> = None;
 
     while buf.has_remaining() {
         // Bluetooth Specification Version 5.2, Vol 3, part C, 8 EXTENDED INQUIRY RESPONSE DATA FORMAT
@@ -139,32 +266,44 @@ pub fn parse_eir<T: Buf>(mut buf: T) -> Result<Vec<EIR>, EIRError> {
                     uuid16_idx = Some(eir.len());
                     eir.push(EIR::Uuid16(Vec::new()));
                 }
-                if let EIR::Uuid16(mut uuid16data) = &eir[uuid16_idx.unwrap()] {
+                if let EIR::Uuid16(uuid16data) = &mut eir[uuid16_idx.unwrap()] {
                     while data.has_remaining() {
                         uuid16data.push(data.get_u16_le());
                     }
                 }
             }
-            // Some(EIRDataTypes::UUID32Incomplete) | Some(EIRDataTypes::UUID32Complete) => {
-            //     if data.remaining() % 4 != 0 {
-            //         return Err(EIRError::UnexpectedDataLength {
-            //             len: data.remaining(),
-            //         });
-            //     }
-            //     while data.has_remaining() {
-            //         eir.uuid32.push(data.get_u32_le());
-            //     }
-            // }
-            // Some(EIRDataTypes::UUID128Incomplete) | Some(EIRDataTypes::UUID128Complete) => {
-            //     if data.remaining() % 16 != 0 {
-            //         return Err(EIRError::UnexpectedDataLength {
-            //             len: data.remaining(),
-            //         });
-            //     }
-            //     while data.has_remaining() {
-            //         eir.uuid128.push(data.get_u128_le());
-            //     }
-            // }
+            Some(EIRDataTypes::UUID32Incomplete) | Some(EIRDataTypes::UUID32Complete) => {
+                if data.remaining() % 4 != 0 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if uuid32_idx.is_none() {
+                    uuid32_idx = Some(eir.len());
+                    eir.push(EIR::Uuid32(Vec::new()));
+                }
+                if let EIR::Uuid32(uuid32data) = &mut eir[uuid32_idx.unwrap()] {
+                    while data.has_remaining() {
+                        uuid32data.push(data.get_u32_le());
+                    }
+                }
+            }
+            Some(EIRDataTypes::UUID128Incomplete) | Some(EIRDataTypes::UUID128Complete) => {
+                if data.remaining() % 16 != 0 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if uuid128_idx.is_none() {
+                    uuid128_idx = Some(eir.len());
+                    eir.push(EIR::Uuid128(Vec::new()));
+                }
+                if let EIR::Uuid128(uuid128data) = &mut eir[uuid128_idx.unwrap()] {
+                    while data.has_remaining() {
+                        uuid128data.push(data.get_u128_le());
+                    }
+                }
+            }
             Some(EIRDataTypes::NameShort) => {
                 if has_name {
                     return Err(EIRError::RepeatedName);
@@ -185,33 +324,119 @@ pub fn parse_eir<T: Buf>(mut buf: T) -> Result<Vec<EIR>, EIRError> {
                     true,
                 ));
             }
-            // Some(EIRDataTypes::TxPowerLevel) => {
-            //     eir.tx_power_level.push(data.get_i8());
-            // }
-            // Some(EIRDataTypes::URI) => {
-            //     let uri_scheme = data.get_u8();
-            //     if uri_scheme == 0x01 {
-            //         let uri = String::from_utf8(data.bytes().to_vec());
-            //         if uri.is_err() {
-            //             return Err(EIRError::InvalidURI);
-            //         }
-            //         eir.uri.push(uri.unwrap());
-            //     } else {
-            //         // TODO: URI scheme translation. Skip for now.
-            //     }
-            // }
-            // Some(EIRDataTypes::ManufacturerSpecificData) => {
-            //     if data.remaining() < 2 {
-            //         return Err(EIRError::UnexpectedDataLength {
-            //             len: data.remaining(),
-            //         });
-            //     }
-            //     eir.manufacturer_specific_data
-            //         .push(ManufacturerSpecificData {
-            //             company_identifier_code: data.get_u16_le(),
-            //             data: Bytes::copy_from_slice(data.bytes()),
-            //         });
-            // }
+            Some(EIRDataTypes::TxPowerLevel) => {
+                if data.remaining() != 1 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if tx_power_idx.is_none() {
+                    tx_power_idx = Some(eir.len());
+                    eir.push(EIR::TxPowerLevel(Vec::new()));
+                }
+                if let EIR::TxPowerLevel(tx_power_data) = &mut eir[tx_power_idx.unwrap()] {
+                    tx_power_data.push(data.get_i8());
+                }
+            }
+            Some(EIRDataTypes::ServiceData16) => {
+                if data.remaining() < 2 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if service_data_idx.is_none() {
+                    service_data_idx = Some(eir.len());
+                    eir.push(EIR::ServiceData(Vec::new()));
+                }
+                if let EIR::ServiceData(service_data) = &mut eir[service_data_idx.unwrap()] {
+                    service_data.push(ServiceData {
+                        uuid: data.get_u16_le() as u128,
+                        width: UuidWidth::Bits16,
+                        data: Bytes::copy_from_slice(data.bytes()),
+                    });
+                }
+            }
+            Some(EIRDataTypes::ServiceData32) => {
+                if data.remaining() < 4 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if service_data_idx.is_none() {
+                    service_data_idx = Some(eir.len());
+                    eir.push(EIR::ServiceData(Vec::new()));
+                }
+                if let EIR::ServiceData(service_data) = &mut eir[service_data_idx.unwrap()] {
+                    service_data.push(ServiceData {
+                        uuid: data.get_u32_le() as u128,
+                        width: UuidWidth::Bits32,
+                        data: Bytes::copy_from_slice(data.bytes()),
+                    });
+                }
+            }
+            Some(EIRDataTypes::ServiceData128) => {
+                if data.remaining() < 16 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if service_data_idx.is_none() {
+                    service_data_idx = Some(eir.len());
+                    eir.push(EIR::ServiceData(Vec::new()));
+                }
+                if let EIR::ServiceData(service_data) = &mut eir[service_data_idx.unwrap()] {
+                    service_data.push(ServiceData {
+                        uuid: data.get_u128_le(),
+                        width: UuidWidth::Bits128,
+                        data: Bytes::copy_from_slice(data.bytes()),
+                    });
+                }
+            }
+            Some(EIRDataTypes::URI) => {
+                if data.remaining() < 1 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                let scheme_code = data.get_u8();
+                let suffix = match std::str::from_utf8(data.bytes()) {
+                    Ok(suffix) => suffix,
+                    Err(_) => return Err(EIRError::InvalidURI),
+                };
+                let prefix = if scheme_code == 0x00 {
+                    ""
+                } else {
+                    uri_scheme_prefix(scheme_code)
+                        .ok_or(EIRError::UnknownURIScheme { code: scheme_code })?
+                };
+
+                if uri_idx.is_none() {
+                    uri_idx = Some(eir.len());
+                    eir.push(EIR::Uri(Vec::new()));
+                }
+                if let EIR::Uri(uris) = &mut eir[uri_idx.unwrap()] {
+                    uris.push(format!("{}{}", prefix, suffix));
+                }
+            }
+            Some(EIRDataTypes::ManufacturerSpecificData) => {
+                if data.remaining() < 2 {
+                    return Err(EIRError::UnexpectedDataLength {
+                        len: data.remaining(),
+                    });
+                }
+                if manufacturer_idx.is_none() {
+                    manufacturer_idx = Some(eir.len());
+                    eir.push(EIR::ManufacturerSpecificData(Vec::new()));
+                }
+                if let EIR::ManufacturerSpecificData(manufacturer_data) =
+                    &mut eir[manufacturer_idx.unwrap()]
+                {
+                    manufacturer_data.push(ManufacturerSpecificData {
+                        company_identifier_code: data.get_u16_le(),
+                        data: Bytes::copy_from_slice(data.bytes()),
+                    });
+                }
+            }
             _ => {
                 // Skip unknown data
             }
@@ -223,6 +448,128 @@ pub fn parse_eir<T: Buf>(mut buf: T) -> Result<Vec<EIR>, EIRError> {
     Ok(eir)
 }
 
+/// Maximum size, in bytes, of a legacy (non-extended) advertising or scan response payload.
+///
+/// See Bluetooth Core Specification v5.2, Vol 3, Part C, 11.
+pub const MAX_LEGACY_ADV_LEN: usize = 31;
+
+/// Serializes a list of `EIR` structures into Extended Inquiry Response (EIR) Data, suitable
+/// for use as advertising data or scan response data.
+///
+/// UUID lists are written little-endian, names carry the short/complete type octet, flags are
+/// collapsed into a single bitfield octet, and manufacturer specific data is prefixed with its
+/// little-endian company identifier code.
+///
+/// Returns `EIRError::AdvertisingDataTooLong` if the encoded structures would not fit within
+/// the `MAX_LEGACY_ADV_LEN` byte legacy advertising limit.
+pub fn encode_eir(eir: &[EIR]) -> Result<Bytes, EIRError> {
+    let mut buf = BytesMut::new();
+
+    for entry in eir {
+        match entry {
+            EIR::Flags(flags) => {
+                encode_structure(&mut buf, EIRDataTypes::Flags as u8, &[flags.bits()])?;
+            }
+            EIR::Uuid16(uuids) => {
+                let mut data = BytesMut::with_capacity(uuids.len() * 2);
+                for uuid in uuids {
+                    data.put_u16_le(*uuid);
+                }
+                encode_structure(&mut buf, EIRDataTypes::UUID16Complete as u8, &data)?;
+            }
+            EIR::Uuid32(uuids) => {
+                let mut data = BytesMut::with_capacity(uuids.len() * 4);
+                for uuid in uuids {
+                    data.put_u32_le(*uuid);
+                }
+                encode_structure(&mut buf, EIRDataTypes::UUID32Complete as u8, &data)?;
+            }
+            EIR::Uuid128(uuids) => {
+                let mut data = BytesMut::with_capacity(uuids.len() * 16);
+                for uuid in uuids {
+                    data.put_u128_le(*uuid);
+                }
+                encode_structure(&mut buf, EIRDataTypes::UUID128Complete as u8, &data)?;
+            }
+            EIR::Name(name, complete) => {
+                let data_type = if *complete {
+                    EIRDataTypes::NameComplete
+                } else {
+                    EIRDataTypes::NameShort
+                };
+                encode_structure(&mut buf, data_type as u8, name.as_bytes())?;
+            }
+            EIR::TxPowerLevel(levels) => {
+                for level in levels {
+                    encode_structure(&mut buf, EIRDataTypes::TxPowerLevel as u8, &[*level as u8])?;
+                }
+            }
+            EIR::Uri(uris) => {
+                for uri in uris {
+                    let (scheme_code, suffix) = encode_uri_scheme(uri);
+                    let mut data = BytesMut::with_capacity(suffix.len() + 1);
+                    data.put_u8(scheme_code);
+                    data.put_slice(suffix.as_bytes());
+                    encode_structure(&mut buf, EIRDataTypes::URI as u8, &data)?;
+                }
+            }
+            EIR::ManufacturerSpecificData(entries) => {
+                for entry in entries {
+                    let mut data = BytesMut::with_capacity(2 + entry.data.len());
+                    data.put_u16_le(entry.company_identifier_code);
+                    data.put_slice(&entry.data);
+                    encode_structure(
+                        &mut buf,
+                        EIRDataTypes::ManufacturerSpecificData as u8,
+                        &data,
+                    )?;
+                }
+            }
+            EIR::ServiceData(entries) => {
+                for entry in entries {
+                    match entry.width {
+                        UuidWidth::Bits16 => {
+                            let mut data = BytesMut::with_capacity(2 + entry.data.len());
+                            data.put_u16_le(entry.uuid as u16);
+                            data.put_slice(&entry.data);
+                            encode_structure(&mut buf, EIRDataTypes::ServiceData16 as u8, &data)?;
+                        }
+                        UuidWidth::Bits32 => {
+                            let mut data = BytesMut::with_capacity(4 + entry.data.len());
+                            data.put_u32_le(entry.uuid as u32);
+                            data.put_slice(&entry.data);
+                            encode_structure(&mut buf, EIRDataTypes::ServiceData32 as u8, &data)?;
+                        }
+                        UuidWidth::Bits128 => {
+                            let mut data = BytesMut::with_capacity(16 + entry.data.len());
+                            data.put_u128_le(entry.uuid);
+                            data.put_slice(&entry.data);
+                            encode_structure(&mut buf, EIRDataTypes::ServiceData128 as u8, &data)?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(buf.freeze())
+}
+
+/// Appends a single `[length, type, data...]` EIR structure to `buf`, rejecting it if doing so
+/// would exceed `MAX_LEGACY_ADV_LEN`.
+fn encode_structure(buf: &mut BytesMut, data_type: u8, data: &[u8]) -> Result<(), EIRError> {
+    let structure_len = data.len() + 1;
+    let total_len = buf.len() + structure_len + 1;
+    if total_len > MAX_LEGACY_ADV_LEN {
+        return Err(EIRError::AdvertisingDataTooLong { len: total_len });
+    }
+
+    buf.put_u8(structure_len as u8);
+    buf.put_u8(data_type);
+    buf.put_slice(data);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,6 +589,137 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn encode_eir_name_test() {
+        let encoded = encode_eir(&[EIR::Name("ABC".to_string(), false)]).unwrap();
+        assert_eq!(&encoded[..], b"\x04\x08ABC");
+    }
+
+    #[test]
+    pub fn encode_eir_too_long_test() {
+        let eir = [EIR::Name("a".repeat(30), true)];
+        assert!(matches!(
+            encode_eir(&eir),
+            Err(EIRError::AdvertisingDataTooLong { .. })
+        ));
+    }
+
+    #[test]
+    pub fn eir_uuid32_test() {
+        let input = Bytes::copy_from_slice(b"\x05\x05\xAB\xAC\xAD\xAE");
+        let eir = parse_eir(input).unwrap();
+        assert_eq!(eir.len(), 1);
+        if let EIR::Uuid32(uuids) = &eir[0] {
+            assert_eq!(uuids, &vec![0xAEAD_ACAB]);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    pub fn eir_tx_power_test() {
+        let input = Bytes::copy_from_slice(b"\x02\x0A\xEC");
+        let eir = parse_eir(input).unwrap();
+        assert_eq!(eir.len(), 1);
+        if let EIR::TxPowerLevel(levels) = &eir[0] {
+            assert_eq!(levels, &vec![-20i8]);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    pub fn eir_manufacturer_data_test() {
+        let input = Bytes::copy_from_slice(b"\x04\xFF\x4C\x00\x02");
+        let eir = parse_eir(input).unwrap();
+        assert_eq!(eir.len(), 1);
+        if let EIR::ManufacturerSpecificData(entries) = &eir[0] {
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].company_identifier_code, 0x004C);
+            assert_eq!(&entries[0].data[..], b"\x02");
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    pub fn eir_service_data_16_test() {
+        let input = Bytes::copy_from_slice(b"\x04\x16\x0F\x18\x01");
+        let eir = parse_eir(input).unwrap();
+        assert_eq!(eir.len(), 1);
+        if let EIR::ServiceData(entries) = &eir[0] {
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].uuid, 0x180F);
+            assert_eq!(&entries[0].data[..], b"\x01");
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    pub fn to_uuid128_test() {
+        assert_eq!(
+            to_uuid128(0x180F),
+            0x0000_180F_0000_1000_8000_0080_5F9B_34FB
+        );
+    }
+
+    #[test]
+    pub fn eir_uuids_expansion_test() {
+        let eir = EIR::Uuid16(vec![0x180F]);
+        assert_eq!(
+            eir.uuids(),
+            vec![Uuid::from_u128(0x0000_180F_0000_1000_8000_0080_5F9B_34FB)]
+        );
+    }
+
+    #[test]
+    pub fn eir_uri_scheme_test() {
+        let input = Bytes::copy_from_slice(b"\x0D\x24\x17example.com");
+        let eir = parse_eir(input).unwrap();
+        assert_eq!(eir.len(), 1);
+        if let EIR::Uri(uris) = &eir[0] {
+            assert_eq!(uris, &vec!["https:example.com".to_string()]);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    pub fn eir_uri_inline_test() {
+        let input = Bytes::copy_from_slice(b"\x09\x24\x00urn:foo");
+        let eir = parse_eir(input).unwrap();
+        if let EIR::Uri(uris) = &eir[0] {
+            assert_eq!(uris, &vec!["urn:foo".to_string()]);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    pub fn eir_uri_empty_test() {
+        let input = Bytes::copy_from_slice(b"\x01\x24");
+        assert!(matches!(
+            parse_eir(input),
+            Err(EIRError::UnexpectedDataLength { len: 0 })
+        ));
+    }
+
+    #[test]
+    pub fn eir_uri_unknown_scheme_test() {
+        let input = Bytes::copy_from_slice(b"\x02\x24\x02");
+        assert!(matches!(
+            parse_eir(input),
+            Err(EIRError::UnknownURIScheme { code: 0x02 })
+        ));
+    }
+
+    #[test]
+    pub fn encode_eir_uri_test() {
+        let encoded = encode_eir(&[EIR::Uri(vec!["https:example.com".to_string()])]).unwrap();
+        assert_eq!(&encoded[..], b"\x0D\x24\x17example.com");
+    }
+
     // #[test]
     // pub fn eir_multiple_test() {
     //     let input = Bytes::copy_from_slice(b"\x02\x01\x06\x03\x03\xAB\xAC\x03\x08Hi");
@@ -266,4 +744,18 @@ mod tests {
     //     assert!(eir.uri.is_empty());
     //     assert!(eir.manufacturer_specific_data.is_empty());
     // }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn eir_serde_roundtrip_test() {
+        let eir = EIR::Name("ABC".to_string(), true);
+        let json = serde_json::to_string(&eir).unwrap();
+        let deserialized: EIR = serde_json::from_str(&json).unwrap();
+        if let EIR::Name(name, complete) = deserialized {
+            assert_eq!(name, "ABC");
+            assert!(complete);
+        } else {
+            unreachable!();
+        }
+    }
 }
@@ -0,0 +1,211 @@
+//! Device filtering over parsed Extended Inquiry Response (EIR) Data, modeled on the Web
+//! Bluetooth `RequestDeviceFilter`
+//! (https://webbluetoothcg.github.io/web-bluetooth/#device-discovery).
+
+use crate::eir::{to_uuid128, EIR};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Error, Debug)]
+pub enum FilterError {
+    #[error("Mask length {} does not match data prefix length {}.", mask_len, data_len)]
+    MaskLengthMismatch { data_len: usize, mask_len: usize },
+    #[error("A device filter must specify at least one criterion.")]
+    EmptyFilter,
+}
+
+/// Matches manufacturer or service data identified by `key` (a company id or service UUID)
+/// against a byte prefix, optionally AND-ing both sides with `mask` before comparing.
+#[derive(Debug, Clone)]
+pub struct DataFilter<K> {
+    key: K,
+    data_prefix: Vec<u8>,
+    mask: Option<Vec<u8>>,
+}
+
+impl<K: Eq> DataFilter<K> {
+    pub fn new(key: K, data_prefix: Vec<u8>, mask: Option<Vec<u8>>) -> Result<Self, FilterError> {
+        if mask.as_ref().is_some_and(|mask| mask.len() != data_prefix.len()) {
+            return Err(FilterError::MaskLengthMismatch {
+                data_len: data_prefix.len(),
+                mask_len: mask.unwrap().len(),
+            });
+        }
+
+        Ok(DataFilter {
+            key,
+            data_prefix,
+            mask,
+        })
+    }
+
+    fn matches(&self, key: &K, data: &[u8]) -> bool {
+        if key != &self.key || data.len() < self.data_prefix.len() {
+            return false;
+        }
+
+        match &self.mask {
+            Some(mask) => self
+                .data_prefix
+                .iter()
+                .zip(mask.iter())
+                .zip(data.iter())
+                .all(|((prefix, mask), byte)| (prefix & mask) == (byte & mask)),
+            None => data.starts_with(&self.data_prefix),
+        }
+    }
+}
+
+/// A Web-Bluetooth-style device filter: a `Vec<EIR>` from `parse_eir` matches if it satisfies
+/// every criterion the filter specifies.
+#[derive(Debug, Clone, Default)]
+pub struct DeviceFilter {
+    services: Vec<Uuid>,
+    name: Option<String>,
+    name_prefix: Option<String>,
+    manufacturer_data: Vec<DataFilter<u16>>,
+    service_data: Vec<DataFilter<Uuid>>,
+}
+
+impl DeviceFilter {
+    /// Builds a filter from its criteria, rejecting an all-empty filter since it would match
+    /// every device.
+    pub fn new(
+        services: Vec<Uuid>,
+        name: Option<String>,
+        name_prefix: Option<String>,
+        manufacturer_data: Vec<DataFilter<u16>>,
+        service_data: Vec<DataFilter<Uuid>>,
+    ) -> Result<Self, FilterError> {
+        let filter = DeviceFilter {
+            services,
+            name,
+            name_prefix,
+            manufacturer_data,
+            service_data,
+        };
+
+        if filter.is_empty() {
+            return Err(FilterError::EmptyFilter);
+        }
+
+        Ok(filter)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.services.is_empty()
+            && self.name.is_none()
+            && self.name_prefix.is_none()
+            && self.manufacturer_data.is_empty()
+            && self.service_data.is_empty()
+    }
+
+    /// Returns whether `eir` satisfies every criterion in this filter.
+    pub fn matches(&self, eir: &[EIR]) -> bool {
+        if !self.services.is_empty() && !self.matches_services(eir) {
+            return false;
+        }
+
+        if (self.name.is_some() || self.name_prefix.is_some()) && !self.matches_name(eir) {
+            return false;
+        }
+
+        self.manufacturer_data
+            .iter()
+            .all(|filter| self.matches_manufacturer_data(eir, filter))
+            && self
+                .service_data
+                .iter()
+                .all(|filter| self.matches_service_data(eir, filter))
+    }
+
+    fn matches_services(&self, eir: &[EIR]) -> bool {
+        let advertised: Vec<Uuid> = eir.iter().flat_map(EIR::uuids).collect();
+        self.services.iter().all(|uuid| advertised.contains(uuid))
+    }
+
+    fn matches_name(&self, eir: &[EIR]) -> bool {
+        let advertised_name = eir.iter().find_map(|entry| match entry {
+            EIR::Name(name, _) => Some(name.as_str()),
+            _ => None,
+        });
+
+        match advertised_name {
+            Some(advertised_name) => {
+                self.name.as_deref().is_none_or(|name| advertised_name == name)
+                    && self
+                        .name_prefix
+                        .as_deref()
+                        .is_none_or(|prefix| advertised_name.starts_with(prefix))
+            }
+            None => false,
+        }
+    }
+
+    fn matches_manufacturer_data(&self, eir: &[EIR], filter: &DataFilter<u16>) -> bool {
+        eir.iter().any(|entry| match entry {
+            EIR::ManufacturerSpecificData(entries) => entries
+                .iter()
+                .any(|data| filter.matches(&data.company_identifier_code(), data.data())),
+            _ => false,
+        })
+    }
+
+    fn matches_service_data(&self, eir: &[EIR], filter: &DataFilter<Uuid>) -> bool {
+        eir.iter().any(|entry| match entry {
+            EIR::ServiceData(entries) => entries.iter().any(|data| {
+                filter.matches(&Uuid::from_u128(data.uuid128()), data.data())
+            }),
+            _ => false,
+        })
+    }
+}
+
+/// Convenience conversion from a raw 16/32-bit UUID to the `Uuid` values `DeviceFilter` expects.
+pub fn service_uuid(short: u32) -> Uuid {
+    Uuid::from_u128(to_uuid128(short))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eir::parse_eir;
+    use bytes::Bytes;
+
+    #[test]
+    pub fn empty_filter_rejected_test() {
+        assert!(matches!(
+            DeviceFilter::new(vec![], None, None, vec![], vec![]),
+            Err(FilterError::EmptyFilter)
+        ));
+    }
+
+    #[test]
+    pub fn mask_length_mismatch_rejected_test() {
+        assert!(matches!(
+            DataFilter::new(0x004Cu16, vec![0x02], Some(vec![0xFF, 0xFF])),
+            Err(FilterError::MaskLengthMismatch { .. })
+        ));
+    }
+
+    #[test]
+    pub fn name_prefix_matches_test() {
+        let filter =
+            DeviceFilter::new(vec![], None, Some("iPhone".to_string()), vec![], vec![]).unwrap();
+        let eir = vec![EIR::Name("iPhone 15".to_string(), true)];
+        assert!(filter.matches(&eir));
+
+        let eir = vec![EIR::Name("Pixel 8".to_string(), true)];
+        assert!(!filter.matches(&eir));
+    }
+
+    #[test]
+    pub fn manufacturer_data_prefix_matches_test() {
+        let data_filter = DataFilter::new(0x004Cu16, vec![0x02], None).unwrap();
+        let filter = DeviceFilter::new(vec![], None, None, vec![data_filter], vec![]).unwrap();
+
+        let input = Bytes::copy_from_slice(b"\x04\xFF\x4C\x00\x02");
+        let eir = parse_eir(input).unwrap();
+        assert!(filter.matches(&eir));
+    }
+}